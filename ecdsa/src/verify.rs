@@ -7,19 +7,25 @@ use crate::{
     hazmat::{DigestPrimitive, VerifyPrimitive},
     Error, Signature, SignatureSize,
 };
-use core::{fmt::Debug, ops::Add};
+use core::{
+    fmt::Debug,
+    ops::{Add, Mul},
+};
 use elliptic_curve::{
     consts::U1,
-    ff::PrimeField,
-    generic_array::ArrayLength,
+    ff::{Field, PrimeField},
+    generic_array::{typenum::Unsigned, ArrayLength},
+    group::Group,
     point::{AffinePoint, ProjectivePoint},
     sec1::{
-        EncodedPoint, FromEncodedPoint, ToEncodedPoint, UncompressedPointSize, UntaggedPointSize,
+        CompressedPointSize, DecompressPoint, EncodedPoint, FromEncodedPoint, ToEncodedPoint,
+        UncompressedPointSize, UntaggedPointSize,
     },
     weierstrass::{point, Curve},
     FieldBytes, FromDigest, ProjectiveArithmetic, PublicKey, Scalar,
 };
-use signature::{digest::Digest, DigestVerifier};
+use signature::{digest::Digest, hazmat::PrehashVerifier, DigestVerifier};
+use subtle::ConstantTimeEq;
 
 #[cfg(feature = "pkcs8")]
 use crate::{
@@ -30,6 +36,18 @@ use crate::{
 #[cfg(feature = "pem")]
 use core::str::FromStr;
 
+#[cfg(feature = "pem")]
+use elliptic_curve::alloc::string::String;
+
+#[cfg(feature = "serde")]
+use serdect::serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+#[cfg(feature = "jwk")]
+use elliptic_curve::{alloc::string::String, jwk::JwkEcKey, JwkParameters};
+
+#[cfg(feature = "pkcs8")]
+use pkcs8::ObjectIdentifier;
+
 /// ECDSA verify key
 #[derive(Copy, Clone, Debug)]
 pub struct VerifyingKey<C>
@@ -87,6 +105,27 @@ where
     }
 }
 
+impl<C> PrehashVerifier<Signature<C>> for VerifyingKey<C>
+where
+    C: Curve + ProjectiveArithmetic,
+    AffinePoint<C>: Copy + Clone + Debug + VerifyPrimitive<C>,
+    ProjectivePoint<C>: From<AffinePoint<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>> + FromDigest<C>,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    /// Verify the signature against a prehashed message digest's raw bytes,
+    /// without requiring a [`Digest`] instance.
+    fn verify_prehash(&self, prehash: &[u8], signature: &Signature<C>) -> Result<(), Error> {
+        if prehash.len() != C::FieldSize::to_usize() {
+            return Err(Error::new());
+        }
+
+        let z = scalar_from_prehash::<C>(&FieldBytes::<C>::clone_from_slice(prehash))?;
+
+        self.inner.as_affine().verify_prehashed(&z, signature)
+    }
+}
+
 impl<C> signature::Verifier<Signature<C>> for VerifyingKey<C>
 where
     C: Curve + ProjectiveArithmetic + DigestPrimitive,
@@ -101,6 +140,140 @@ where
     }
 }
 
+/// OID for `ecdsa-with-SHA224` signatures (RFC 5758).
+#[cfg(feature = "pkcs8")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pkcs8")))]
+pub const ECDSA_SHA224_OID: ObjectIdentifier = ObjectIdentifier::new("1.2.840.10045.4.3.1");
+
+/// OID for `ecdsa-with-SHA256` signatures (RFC 5758).
+#[cfg(feature = "pkcs8")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pkcs8")))]
+pub const ECDSA_SHA256_OID: ObjectIdentifier = ObjectIdentifier::new("1.2.840.10045.4.3.2");
+
+/// OID for `ecdsa-with-SHA384` signatures (RFC 5758).
+#[cfg(feature = "pkcs8")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pkcs8")))]
+pub const ECDSA_SHA384_OID: ObjectIdentifier = ObjectIdentifier::new("1.2.840.10045.4.3.3");
+
+/// OID for `ecdsa-with-SHA512` signatures (RFC 5758).
+#[cfg(feature = "pkcs8")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pkcs8")))]
+pub const ECDSA_SHA512_OID: ObjectIdentifier = ObjectIdentifier::new("1.2.840.10045.4.3.4");
+
+/// Look up the `ecdsa-with-SHA*` OID matching the given [`Digest`], for the
+/// handful of digest output sizes RFC 5758 assigns an OID to.
+#[cfg(feature = "pkcs8")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pkcs8")))]
+pub fn ecdsa_oid_for_digest<D: Digest>() -> Option<ObjectIdentifier> {
+    match D::output_size() {
+        28 => Some(ECDSA_SHA224_OID),
+        32 => Some(ECDSA_SHA256_OID),
+        48 => Some(ECDSA_SHA384_OID),
+        64 => Some(ECDSA_SHA512_OID),
+        _ => None,
+    }
+}
+
+/// An ECDSA [`Signature`] paired with the `ecdsa-with-SHA*` OID of the
+/// digest algorithm it was computed over, as embedded in an X.509/CMS
+/// `AlgorithmIdentifier`.
+#[cfg(feature = "pkcs8")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pkcs8")))]
+#[derive(Clone, Debug)]
+pub struct SignatureWithOid<C>
+where
+    C: Curve + ProjectiveArithmetic,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    signature: Signature<C>,
+    digest_oid: ObjectIdentifier,
+}
+
+#[cfg(feature = "pkcs8")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pkcs8")))]
+impl<C> SignatureWithOid<C>
+where
+    C: Curve + ProjectiveArithmetic,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    /// Pair the given `signature` with `digest_oid`, rejecting digest OIDs
+    /// this crate doesn't recognize as an `ecdsa-with-SHA*` algorithm.
+    pub fn new(signature: Signature<C>, digest_oid: ObjectIdentifier) -> Result<Self, Error> {
+        match digest_oid {
+            ECDSA_SHA224_OID | ECDSA_SHA256_OID | ECDSA_SHA384_OID | ECDSA_SHA512_OID => {
+                Ok(Self {
+                    signature,
+                    digest_oid,
+                })
+            }
+            _ => Err(Error::new()),
+        }
+    }
+
+    /// Borrow the inner [`Signature`].
+    pub fn signature(&self) -> &Signature<C> {
+        &self.signature
+    }
+
+    /// Get the digest algorithm OID this signature was computed over.
+    pub fn digest_oid(&self) -> ObjectIdentifier {
+        self.digest_oid
+    }
+}
+
+/// Hash `msg` with `D` and reduce the digest into a `Scalar<C>`.
+///
+/// ANSI X9.62 has the digest and the field use different widths: if `D`'s
+/// output is wider than the field, only the leftmost `C::FieldSize` bytes
+/// are used; if it's narrower, it's zero-padded on the left.
+#[cfg(all(feature = "pkcs8", feature = "sha2"))]
+fn hash_and_reduce<C, D>(msg: &[u8]) -> Result<Scalar<C>, Error>
+where
+    C: Curve + ProjectiveArithmetic,
+    D: Digest,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+{
+    let digest = D::digest(msg);
+    let field_size = C::FieldSize::to_usize();
+    let mut prehash = FieldBytes::<C>::default();
+
+    if digest.len() >= field_size {
+        prehash.copy_from_slice(&digest[..field_size]);
+    } else {
+        prehash[field_size - digest.len()..].copy_from_slice(&digest);
+    }
+
+    scalar_from_prehash::<C>(&prehash)
+}
+
+#[cfg(all(feature = "pkcs8", feature = "sha2"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "pkcs8", feature = "sha2"))))]
+impl<C> signature::Verifier<SignatureWithOid<C>> for VerifyingKey<C>
+where
+    C: Curve + ProjectiveArithmetic,
+    AffinePoint<C>: Copy + Clone + Debug + VerifyPrimitive<C>,
+    ProjectivePoint<C>: From<AffinePoint<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    /// Verify `signature`, picking the digest algorithm from its embedded
+    /// `digest_oid` rather than hard-coding `C::Digest`, then hashing
+    /// `msg` with that digest and verifying.
+    fn verify(&self, msg: &[u8], signature: &SignatureWithOid<C>) -> Result<(), Error> {
+        let z = match signature.digest_oid() {
+            ECDSA_SHA224_OID => hash_and_reduce::<C, sha2::Sha224>(msg)?,
+            ECDSA_SHA256_OID => hash_and_reduce::<C, sha2::Sha256>(msg)?,
+            ECDSA_SHA384_OID => hash_and_reduce::<C, sha2::Sha384>(msg)?,
+            ECDSA_SHA512_OID => hash_and_reduce::<C, sha2::Sha512>(msg)?,
+            _ => return Err(Error::new()),
+        };
+
+        self.inner
+            .as_affine()
+            .verify_prehashed(&z, signature.signature())
+    }
+}
+
 impl<C> From<&VerifyingKey<C>> for EncodedPoint<C>
 where
     C: Curve + ProjectiveArithmetic + point::Compression,
@@ -184,6 +357,57 @@ where
     }
 }
 
+impl<C> Ord for VerifyingKey<C>
+where
+    C: Curve + ProjectiveArithmetic,
+    AffinePoint<C>: Copy + Clone + Debug + Default + FromEncodedPoint<C> + ToEncodedPoint<C>,
+    ProjectivePoint<C>: From<AffinePoint<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+    UntaggedPointSize<C>: Add<U1> + ArrayLength<u8>,
+    UncompressedPointSize<C>: ArrayLength<u8>,
+{
+    /// Order `VerifyingKey`s lexicographically by their compressed SEC1
+    /// encoding, so they can be used as `BTreeMap`/`BTreeSet` keys.
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.to_encoded_point(true)
+            .as_bytes()
+            .cmp(other.to_encoded_point(true).as_bytes())
+    }
+}
+
+impl<C> PartialOrd for VerifyingKey<C>
+where
+    C: Curve + ProjectiveArithmetic,
+    AffinePoint<C>: Copy + Clone + Debug + Default + FromEncodedPoint<C> + ToEncodedPoint<C>,
+    ProjectivePoint<C>: From<AffinePoint<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+    UntaggedPointSize<C>: Add<U1> + ArrayLength<u8>,
+    UncompressedPointSize<C>: ArrayLength<u8>,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<C> ConstantTimeEq for VerifyingKey<C>
+where
+    C: Curve + ProjectiveArithmetic,
+    AffinePoint<C>: Copy + Clone + Debug + Default + FromEncodedPoint<C> + ToEncodedPoint<C>,
+    ProjectivePoint<C>: From<AffinePoint<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+    UntaggedPointSize<C>: Add<U1> + ArrayLength<u8>,
+    UncompressedPointSize<C>: ArrayLength<u8>,
+{
+    /// Compare the compressed SEC1 encodings of `self` and `other` in
+    /// constant time, so equality checks don't leak information via an
+    /// early-exit byte comparison.
+    fn ct_eq(&self, other: &Self) -> subtle::Choice {
+        self.to_encoded_point(true)
+            .as_bytes()
+            .ct_eq(other.to_encoded_point(true).as_bytes())
+    }
+}
+
 #[cfg(feature = "pkcs8")]
 #[cfg_attr(docsrs, doc(cfg(feature = "pkcs8")))]
 impl<C> FromPublicKey for VerifyingKey<C>
@@ -200,6 +424,22 @@ where
     }
 }
 
+#[cfg(feature = "pkcs8")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pkcs8")))]
+impl<C> pkcs8::EncodePublicKey for VerifyingKey<C>
+where
+    C: Curve + AlgorithmParameters + ProjectiveArithmetic + point::Compression,
+    AffinePoint<C>: Copy + Clone + Debug + Default + FromEncodedPoint<C> + ToEncodedPoint<C>,
+    ProjectivePoint<C>: From<AffinePoint<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+    UntaggedPointSize<C>: Add<U1> + ArrayLength<u8>,
+    UncompressedPointSize<C>: ArrayLength<u8>,
+{
+    fn to_public_key_der(&self) -> pkcs8::Result<pkcs8::PublicKeyDocument> {
+        self.inner.to_public_key_der()
+    }
+}
+
 #[cfg(feature = "pem")]
 #[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
 impl<C> FromStr for VerifyingKey<C>
@@ -217,3 +457,457 @@ where
         Self::from_public_key_pem(s).map_err(|_| Error::new())
     }
 }
+
+#[cfg(feature = "pem")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+impl<C> VerifyingKey<C>
+where
+    C: Curve + AlgorithmParameters + ProjectiveArithmetic + point::Compression,
+    AffinePoint<C>: Copy + Clone + Debug + Default + FromEncodedPoint<C> + ToEncodedPoint<C>,
+    ProjectivePoint<C>: From<AffinePoint<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+    UntaggedPointSize<C>: Add<U1> + ArrayLength<u8>,
+    UncompressedPointSize<C>: ArrayLength<u8>,
+{
+    /// Serialize this [`VerifyingKey`] as PEM-encoded SPKI with the given
+    /// line ending.
+    pub fn to_public_key_pem(&self, line_ending: pkcs8::LineEnding) -> pkcs8::Result<String> {
+        use pkcs8::EncodePublicKey;
+        self.to_public_key_der()?.to_pem(line_ending)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<C> Serialize for VerifyingKey<C>
+where
+    C: Curve + ProjectiveArithmetic + point::Compression,
+    AffinePoint<C>: Copy + Clone + Debug + Default + FromEncodedPoint<C> + ToEncodedPoint<C>,
+    ProjectivePoint<C>: From<AffinePoint<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+    UntaggedPointSize<C>: Add<U1> + ArrayLength<u8>,
+    UncompressedPointSize<C>: ArrayLength<u8>,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serdect::array::serialize_hex_lower_or_bin(
+            &self.to_encoded_point(true).as_bytes(),
+            serializer,
+        )
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de, C> Deserialize<'de> for VerifyingKey<C>
+where
+    C: Curve + ProjectiveArithmetic + point::Compression,
+    AffinePoint<C>: Copy + Clone + Debug + Default + FromEncodedPoint<C> + ToEncodedPoint<C>,
+    ProjectivePoint<C>: From<AffinePoint<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+    UntaggedPointSize<C>: Add<U1> + ArrayLength<u8>,
+    UncompressedPointSize<C>: ArrayLength<u8>,
+    CompressedPointSize<C>: ArrayLength<u8>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut compressed =
+            elliptic_curve::generic_array::GenericArray::<u8, CompressedPointSize<C>>::default();
+        serdect::array::deserialize_hex_or_bin(&mut compressed, deserializer)?;
+        Self::from_sec1_bytes(&compressed).map_err(de::Error::custom)
+    }
+}
+
+#[cfg(feature = "jwk")]
+#[cfg_attr(docsrs, doc(cfg(feature = "jwk")))]
+impl<C> VerifyingKey<C>
+where
+    C: Curve + JwkParameters + ProjectiveArithmetic,
+    AffinePoint<C>: Copy + Clone + Debug,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+{
+    /// Parse a [`VerifyingKey`] from a JSON Web Key (JWK).
+    pub fn from_jwk(jwk: &JwkEcKey) -> Result<Self, Error> {
+        PublicKey::from_jwk(jwk)
+            .map(|inner| Self { inner })
+            .map_err(|_| Error::new())
+    }
+
+    /// Parse a [`VerifyingKey`] from the string encoding of a JSON Web Key
+    /// (JWK).
+    pub fn from_jwk_str(jwk: &str) -> Result<Self, Error> {
+        jwk.parse::<JwkEcKey>()
+            .map_err(|_| Error::new())
+            .and_then(|jwk| Self::from_jwk(&jwk))
+    }
+
+    /// Serialize this [`VerifyingKey`] as a JSON Web Key (JWK).
+    pub fn to_jwk(&self) -> JwkEcKey {
+        self.inner.to_jwk()
+    }
+
+    /// Serialize this [`VerifyingKey`] as the string encoding of a JSON Web
+    /// Key (JWK).
+    pub fn to_jwk_string(&self) -> String {
+        self.to_jwk().to_string()
+    }
+}
+
+/// Identifier used to recover a [`VerifyingKey`] from a signature and its
+/// associated message, a la the `v` value in an Ethereum transaction
+/// signature.
+///
+/// Encodes two bits of information about the point `R` of the `(r, s)`
+/// signature which are lost when the signature is serialized:
+///
+/// - bit 0: the y-parity of `R` (i.e. whether its y-coordinate is odd)
+/// - bit 1: whether the x-coordinate of `R` overflowed the order of the
+///   curve's scalar field, i.e. the true x-coordinate is `r + n` rather
+///   than `r`
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct RecoveryId(u8);
+
+impl RecoveryId {
+    /// Create a new [`RecoveryId`] from the two least-significant bits of
+    /// the given byte.
+    pub const fn new(byte: u8) -> Self {
+        Self(byte & 0b11)
+    }
+
+    /// Did the point `R` have an odd y-coordinate?
+    pub const fn is_y_odd(self) -> bool {
+        self.0 & 0b1 != 0
+    }
+
+    /// Did the x-coordinate of `R` overflow the order of the curve's
+    /// scalar field?
+    pub const fn is_x_reduced(self) -> bool {
+        self.0 & 0b10 != 0
+    }
+
+    /// Convert this [`RecoveryId`] into a single byte.
+    pub const fn to_byte(self) -> u8 {
+        self.0
+    }
+}
+
+impl From<u8> for RecoveryId {
+    fn from(byte: u8) -> Self {
+        Self::new(byte)
+    }
+}
+
+impl From<RecoveryId> for u8 {
+    fn from(recovery_id: RecoveryId) -> u8 {
+        recovery_id.to_byte()
+    }
+}
+
+/// Add two big-endian byte arrays as unsigned integers, returning `None`
+/// if the sum doesn't fit back into the same width.
+fn be_bytes_add<C>(a: &FieldBytes<C>, b: &FieldBytes<C>) -> Option<FieldBytes<C>>
+where
+    C: Curve + ProjectiveArithmetic,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+{
+    let mut out = FieldBytes::<C>::default();
+    let mut carry = 0u16;
+
+    for i in (0..out.len()).rev() {
+        let sum = u16::from(a[i]) + u16::from(b[i]) + carry;
+        out[i] = sum as u8;
+        carry = sum >> 8;
+    }
+
+    if carry == 0 {
+        Some(out)
+    } else {
+        None
+    }
+}
+
+/// Compute the big-endian encoding of the curve's scalar field order `n`.
+///
+/// `Scalar<C>` only ever holds values already reduced mod `n`, so `n`
+/// itself is recovered as `(n - 1) + 1` rather than read off a constant.
+fn order_bytes<C>() -> Option<FieldBytes<C>>
+where
+    C: Curve + ProjectiveArithmetic,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+{
+    let n_minus_one = (-Scalar::<C>::one()).to_repr();
+    let mut one = FieldBytes::<C>::default();
+    *one.last_mut()? = 1;
+    be_bytes_add::<C>(&n_minus_one, &one)
+}
+
+/// Subtract `b` from `a`, both read as big-endian unsigned integers,
+/// returning `None` if `b > a`.
+fn be_bytes_sub<C>(a: &FieldBytes<C>, b: &FieldBytes<C>) -> Option<FieldBytes<C>>
+where
+    C: Curve + ProjectiveArithmetic,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+{
+    let mut out = FieldBytes::<C>::default();
+    let mut borrow = 0i16;
+
+    for i in (0..out.len()).rev() {
+        let diff = i16::from(a[i]) - i16::from(b[i]) - borrow;
+        if diff < 0 {
+            out[i] = (diff + 0x100) as u8;
+            borrow = 1;
+        } else {
+            out[i] = diff as u8;
+            borrow = 0;
+        }
+    }
+
+    if borrow == 0 {
+        Some(out)
+    } else {
+        None
+    }
+}
+
+/// Reduce a field-sized big-endian byte string into a `Scalar<C>` modulo
+/// the curve's order `n`, the same reduction [`FromDigest`] applies to a
+/// hash output. This lets prehash bytes that never passed through a
+/// [`Digest`] instance (e.g. from [`PrehashVerifier::verify_prehash`] or
+/// [`VerifyingKey::recover_from_prehash`]) be treated identically to a
+/// message hashed and verified through [`DigestVerifier::verify_digest`].
+///
+/// `prehash` is assumed to be the same width as the field (as it would be
+/// coming from a `Digest<OutputSize = C::FieldSize>`), so a single
+/// subtraction of `n` suffices to bring it into canonical range.
+fn scalar_from_prehash<C>(prehash: &FieldBytes<C>) -> Result<Scalar<C>, Error>
+where
+    C: Curve + ProjectiveArithmetic,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+{
+    if let Some(z) = Scalar::<C>::from_repr(*prehash) {
+        return Ok(z);
+    }
+
+    let n = order_bytes::<C>().ok_or_else(Error::new)?;
+    let reduced = be_bytes_sub(prehash, &n).ok_or_else(Error::new)?;
+    Scalar::<C>::from_repr(reduced).ok_or_else(Error::new)
+}
+
+impl<C> VerifyingKey<C>
+where
+    C: Curve + ProjectiveArithmetic,
+    AffinePoint<C>: Copy
+        + Clone
+        + Debug
+        + Default
+        + DecompressPoint<C>
+        + FromEncodedPoint<C>
+        + ToEncodedPoint<C>,
+    ProjectivePoint<C>: From<AffinePoint<C>>
+        + Group<Scalar = Scalar<C>>
+        + Mul<Scalar<C>, Output = ProjectivePoint<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>> + FromDigest<C>,
+    SignatureSize<C>: ArrayLength<u8>,
+    UntaggedPointSize<C>: Add<U1> + ArrayLength<u8>,
+    UncompressedPointSize<C>: ArrayLength<u8>,
+{
+    /// Recover a [`VerifyingKey`] from the given message `prehash`,
+    /// `signature`, and `recovery_id`.
+    ///
+    /// The `recovery_id` must be the same one produced when the signature
+    /// was originally computed.
+    pub fn recover_from_prehash(
+        prehash: &FieldBytes<C>,
+        signature: &Signature<C>,
+        recovery_id: RecoveryId,
+    ) -> Result<Self, Error> {
+        let (r, s) = signature.split_scalars();
+        let z = scalar_from_prehash::<C>(prehash)?;
+
+        let x = if recovery_id.is_x_reduced() {
+            // The x-coordinate of `R` overflowed the curve's order, i.e.
+            // the true x-coordinate is `r + n`. `Scalar<C>` has no
+            // infallible way to add the order to itself without wrapping,
+            // so the addition is done byte-wise on `r`'s big-endian
+            // encoding; `AffinePoint::decompress` below already rejects
+            // any `x` that isn't a valid field element, which covers the
+            // "overflows the field modulus" case from the spec.
+            be_bytes_add(&r.to_repr(), &order_bytes::<C>().ok_or_else(Error::new)?)
+                .ok_or_else(Error::new)?
+        } else {
+            r.to_repr()
+        };
+
+        let r_point = Option::<AffinePoint<C>>::from(AffinePoint::<C>::decompress(
+            &x,
+            u8::from(recovery_id.is_y_odd()).into(),
+        ))
+        .ok_or_else(Error::new)?;
+
+        let r_inv = Option::<Scalar<C>>::from(r.invert()).ok_or_else(Error::new)?;
+        let u1 = -(z * r_inv);
+        let u2 = s * r_inv;
+
+        let big_r = ProjectivePoint::<C>::from(r_point);
+        let q = (ProjectivePoint::<C>::generator() * u1) + (big_r * u2);
+
+        if q.is_identity().into() {
+            return Err(Error::new());
+        }
+
+        Ok(Self {
+            inner: PublicKey::from_affine(q.to_affine()).map_err(|_| Error::new())?,
+        })
+    }
+
+    /// Recover a [`VerifyingKey`] from the given `message`, `signature`, and
+    /// `recovery_id`, hashing `message` with `C::Digest`.
+    pub fn recover_from_msg(
+        message: &[u8],
+        signature: &Signature<C>,
+        recovery_id: RecoveryId,
+    ) -> Result<Self, Error>
+    where
+        C: DigestPrimitive,
+        C::Digest: Digest<OutputSize = C::FieldSize>,
+    {
+        Self::recover_from_digest(C::Digest::new().chain(message), signature, recovery_id)
+    }
+
+    /// Recover a [`VerifyingKey`] from the given already-hashed `digest`,
+    /// `signature`, and `recovery_id`.
+    pub fn recover_from_digest<D>(
+        digest: D,
+        signature: &Signature<C>,
+        recovery_id: RecoveryId,
+    ) -> Result<Self, Error>
+    where
+        D: Digest<OutputSize = C::FieldSize>,
+    {
+        Self::recover_from_prehash(&digest.finalize(), signature, recovery_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SigningKey;
+    use p256::NistP256;
+    use sha2::Sha256;
+
+    type Sig = Signature<NistP256>;
+    type VerifyKey = VerifyingKey<NistP256>;
+
+    #[test]
+    fn recover_from_digest_roundtrip() {
+        let signing_key = SigningKey::<NistP256>::random(&mut rand_core::OsRng);
+        let msg = b"recoverable signatures are neat";
+
+        let (signature, recovery_id): (Sig, RecoveryId) =
+            signing_key.sign_digest_recoverable(Sha256::new().chain(msg));
+
+        let recovered =
+            VerifyKey::recover_from_digest(Sha256::new().chain(msg), &signature, recovery_id)
+                .expect("recovery should succeed for a signature `sign_digest_recoverable` made");
+
+        assert_eq!(recovered, *signing_key.verifying_key());
+    }
+
+    #[test]
+    fn recover_from_prehash_roundtrip() {
+        let signing_key = SigningKey::<NistP256>::random(&mut rand_core::OsRng);
+        let prehash: FieldBytes<NistP256> = Sha256::digest(b"a prehashed message");
+
+        let (signature, recovery_id): (Sig, RecoveryId) = signing_key
+            .sign_prehash_recoverable(&prehash)
+            .expect("signing a field-sized prehash should succeed");
+
+        let recovered = VerifyKey::recover_from_prehash(&prehash, &signature, recovery_id)
+            .expect("recovery should succeed for a signature `sign_prehash_recoverable` made");
+
+        assert_eq!(recovered, *signing_key.verifying_key());
+    }
+
+    #[test]
+    fn recover_from_prehash_rejects_x_reduced_and_non_x_reduced_alike() {
+        // A recovery ID claiming the wrong parity or x-reduction bit for a
+        // given signature must not recover a key that verifies the message,
+        // confirming `recover_from_prehash` doesn't silently accept either
+        // recovery bit as a don't-care.
+        let signing_key = SigningKey::<NistP256>::random(&mut rand_core::OsRng);
+        let prehash: FieldBytes<NistP256> = Sha256::digest(b"flip my recovery id");
+
+        let (signature, recovery_id) = signing_key
+            .sign_prehash_recoverable(&prehash)
+            .expect("signing a field-sized prehash should succeed");
+
+        for flip in [0b01, 0b10] {
+            let flipped = RecoveryId::new(recovery_id.to_byte() ^ flip);
+            if let Ok(wrong) = VerifyKey::recover_from_prehash(&prehash, &signature, flipped) {
+                assert_ne!(wrong, *signing_key.verifying_key());
+            }
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_roundtrip() {
+        let signing_key = SigningKey::<NistP256>::random(&mut rand_core::OsRng);
+        let verifying_key = *signing_key.verifying_key();
+
+        let json = serde_json::to_string(&verifying_key).unwrap();
+        let decoded: VerifyKey = serde_json::from_str(&json).unwrap();
+        assert_eq!(verifying_key, decoded);
+    }
+
+    #[cfg(feature = "jwk")]
+    #[test]
+    fn jwk_roundtrip() {
+        let signing_key = SigningKey::<NistP256>::random(&mut rand_core::OsRng);
+        let verifying_key = *signing_key.verifying_key();
+
+        let jwk = verifying_key.to_jwk();
+        assert_eq!(VerifyKey::from_jwk(&jwk).unwrap(), verifying_key);
+
+        let jwk_string = verifying_key.to_jwk_string();
+        assert_eq!(VerifyKey::from_jwk_str(&jwk_string).unwrap(), verifying_key);
+    }
+
+    #[cfg(all(feature = "pkcs8", feature = "pem"))]
+    #[test]
+    fn pkcs8_pem_roundtrip() {
+        use pkcs8::EncodePublicKey;
+
+        let signing_key = SigningKey::<NistP256>::random(&mut rand_core::OsRng);
+        let verifying_key = *signing_key.verifying_key();
+
+        let pem = verifying_key
+            .to_public_key_pem(pkcs8::LineEnding::LF)
+            .unwrap();
+        assert_eq!(VerifyKey::from_str(&pem).unwrap(), verifying_key);
+    }
+
+    #[test]
+    fn ord_is_consistent_with_encoded_point() {
+        let a = *SigningKey::<NistP256>::random(&mut rand_core::OsRng).verifying_key();
+        let b = *SigningKey::<NistP256>::random(&mut rand_core::OsRng).verifying_key();
+
+        assert_eq!(
+            a.cmp(&b),
+            a.to_encoded_point(true)
+                .as_bytes()
+                .cmp(b.to_encoded_point(true).as_bytes())
+        );
+    }
+
+    #[test]
+    fn ct_eq_agrees_with_partial_eq() {
+        let signing_key = SigningKey::<NistP256>::random(&mut rand_core::OsRng);
+        let a = *signing_key.verifying_key();
+        let b = a;
+        let c = *SigningKey::<NistP256>::random(&mut rand_core::OsRng).verifying_key();
+
+        assert!(bool::from(a.ct_eq(&b)));
+        assert!(!bool::from(a.ct_eq(&c)));
+        assert_eq!(bool::from(a.ct_eq(&b)), a == b);
+        assert_eq!(bool::from(a.ct_eq(&c)), a == c);
+    }
+}